@@ -0,0 +1,34 @@
+//! Compares the nibble-lookup `fast_hex` path against the scalar `hex` crate
+//! for the batch sizes `batch_hash` pushes through in the browser. Requires
+//! the `fast-hex` feature: `cargo bench --features fast-hex`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[path = "../src/fast_hex.rs"]
+mod fast_hex;
+
+fn sample_data(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i % 256) as u8).collect()
+}
+
+fn bench_encode(c: &mut Criterion) {
+    let data = sample_data(32 * 1000);
+
+    let mut group = c.benchmark_group("hex_encode_32kb");
+    group.bench_function("hex_crate", |b| b.iter(|| hex::encode(black_box(&data))));
+    group.bench_function("fast_hex", |b| b.iter(|| fast_hex::encode(black_box(&data))));
+    group.finish();
+}
+
+fn bench_decode(c: &mut Criterion) {
+    let data = sample_data(32 * 1000);
+    let encoded = hex::encode(&data);
+
+    let mut group = c.benchmark_group("hex_decode_32kb");
+    group.bench_function("hex_crate", |b| b.iter(|| hex::decode(black_box(&encoded)).unwrap()));
+    group.bench_function("fast_hex", |b| b.iter(|| fast_hex::decode(black_box(&encoded)).unwrap()));
+    group.finish();
+}
+
+criterion_group!(benches, bench_encode, bench_decode);
+criterion_main!(benches);
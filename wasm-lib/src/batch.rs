@@ -0,0 +1,77 @@
+use wasm_bindgen::prelude::*;
+use js_sys;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::hasher::{self, HashAlgorithm};
+
+/// Hashes each input sequentially against the shared scratchpad. This is
+/// `batch_hash`'s implementation, and the fallback `batch_hash_parallel`
+/// uses when the `parallel` feature isn't enabled.
+pub fn batch_hash(
+    data_slices: &js_sys::Array,
+    algorithm: HashAlgorithm,
+) -> Result<js_sys::Array, JsValue> {
+    let results = js_sys::Array::new();
+
+    for i in 0..data_slices.length() {
+        let data = data_slices
+            .get(i)
+            .dyn_into::<js_sys::Uint8Array>()
+            .map_err(|_| JsValue::from_str("Failed to convert input to Uint8Array"))?;
+
+        let hash_result = hasher::hash(&data.to_vec(), algorithm)?;
+        results.push(&js_sys::Uint8Array::from(&hash_result[..]));
+    }
+
+    Ok(results)
+}
+
+/// Hashes each input across a small pool of scratchpads sized to available
+/// parallelism, instead of `batch_hash`'s single mutexed scratchpad. Input
+/// order is preserved in the returned array. Falls back to the sequential
+/// `batch_hash` when the `parallel` feature (and the `wasm-bindgen-rayon`
+/// thread pool it requires) isn't enabled.
+#[cfg(feature = "parallel")]
+pub fn batch_hash_parallel(
+    data_slices: &js_sys::Array,
+    algorithm: HashAlgorithm,
+) -> Result<js_sys::Array, JsValue> {
+    let inputs: Vec<Vec<u8>> = (0..data_slices.length())
+        .map(|i| {
+            data_slices
+                .get(i)
+                .dyn_into::<js_sys::Uint8Array>()
+                .map(|arr| arr.to_vec())
+                .map_err(|_| JsValue::from_str("Failed to convert input to Uint8Array"))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let hashed: Vec<Vec<u8>> = inputs
+        .par_iter()
+        .map(|bytes| match algorithm {
+            HashAlgorithm::XelisV2 => {
+                let slot = rayon::current_thread_index().unwrap_or(0);
+                hasher::pool::hash_xelis_v2(bytes, slot)
+            }
+            other => hasher::hash_raw(bytes, other),
+        })
+        .collect::<Result<_, String>>()
+        .map_err(|e| JsValue::from_str(&e))?;
+
+    let results = js_sys::Array::new();
+    for hash in hashed {
+        results.push(&js_sys::Uint8Array::from(&hash[..]));
+    }
+
+    Ok(results)
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn batch_hash_parallel(
+    data_slices: &js_sys::Array,
+    algorithm: HashAlgorithm,
+) -> Result<js_sys::Array, JsValue> {
+    batch_hash(data_slices, algorithm)
+}
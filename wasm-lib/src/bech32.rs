@@ -0,0 +1,134 @@
+//! Standard bech32 encode/decode, so digests can be rendered in the
+//! checksummed, copy-safe form XELIS uses for addresses and identifiers
+//! instead of raw hex.
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+fn polymod(values: &[u8]) -> u32 {
+    let mut chk: u32 = 1;
+    for &v in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x1ffffff) << 5) ^ (v as u32);
+        for (i, gen) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= gen;
+            }
+        }
+    }
+    chk
+}
+
+fn hrp_expand(hrp: &str) -> Vec<u8> {
+    let mut v = Vec::with_capacity(hrp.len() * 2 + 1);
+    v.extend(hrp.bytes().map(|c| c >> 5));
+    v.push(0);
+    v.extend(hrp.bytes().map(|c| c & 31));
+    v
+}
+
+fn create_checksum(hrp: &str, data: &[u8]) -> [u8; 6] {
+    let mut values = hrp_expand(hrp);
+    values.extend_from_slice(data);
+    values.extend_from_slice(&[0u8; 6]);
+    let mod_ = polymod(&values) ^ 1;
+
+    let mut checksum = [0u8; 6];
+    for (i, slot) in checksum.iter_mut().enumerate() {
+        *slot = ((mod_ >> (5 * (5 - i))) & 31) as u8;
+    }
+    checksum
+}
+
+fn verify_checksum(hrp: &str, data: &[u8]) -> bool {
+    let mut values = hrp_expand(hrp);
+    values.extend_from_slice(data);
+    polymod(&values) == 1
+}
+
+/// Regroups bits between `from_bits`-wide and `to_bits`-wide chunks (e.g. the
+/// 8-bit bytes of a digest into 5-bit bech32 symbols), zero-padding the final
+/// group when `pad` is set.
+fn convert_bits(data: &[u8], from_bits: u32, to_bits: u32, pad: bool) -> Result<Vec<u8>, String> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let mut out = Vec::new();
+    let max_value = (1u32 << to_bits) - 1;
+
+    for &value in data {
+        if (value as u32) >> from_bits != 0 {
+            return Err("input value out of range for bit conversion".to_string());
+        }
+        acc = (acc << from_bits) | value as u32;
+        bits += from_bits;
+        while bits >= to_bits {
+            bits -= to_bits;
+            out.push(((acc >> bits) & max_value) as u8);
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            out.push(((acc << (to_bits - bits)) & max_value) as u8);
+        }
+    } else if bits >= from_bits || ((acc << (to_bits - bits)) & max_value) != 0 {
+        return Err("invalid padding in bit conversion".to_string());
+    }
+
+    Ok(out)
+}
+
+/// Encodes `data` as bech32 with the given human-readable prefix:
+/// `hrp + "1" + data_symbols + checksum`.
+pub fn encode(hrp: &str, data: &[u8]) -> Result<String, String> {
+    if hrp.is_empty() {
+        return Err("hrp must not be empty".to_string());
+    }
+    if !hrp.bytes().all(|c| (33..=126).contains(&c)) {
+        return Err("hrp must contain only printable ASCII".to_string());
+    }
+
+    let values = convert_bits(data, 8, 5, true)?;
+    let checksum = create_checksum(hrp, &values);
+
+    let mut result = String::with_capacity(hrp.len() + 1 + values.len() + checksum.len());
+    result.push_str(hrp);
+    result.push('1');
+    for &v in values.iter().chain(checksum.iter()) {
+        result.push(CHARSET[v as usize] as char);
+    }
+
+    Ok(result)
+}
+
+/// Decodes a bech32 string back into its data bytes, validating the checksum.
+pub fn decode(s: &str) -> Result<Vec<u8>, String> {
+    if s.chars().any(|c| c.is_ascii_uppercase()) && s.chars().any(|c| c.is_ascii_lowercase()) {
+        return Err("bech32 string must not mix upper and lower case".to_string());
+    }
+    let lower = s.to_ascii_lowercase();
+
+    let sep = lower.rfind('1').ok_or_else(|| "missing separator '1'".to_string())?;
+    let (hrp, data_part) = lower.split_at(sep);
+    let data_part = &data_part[1..];
+
+    if hrp.is_empty() || data_part.len() < 6 {
+        return Err("bech32 string is too short".to_string());
+    }
+
+    let mut values = Vec::with_capacity(data_part.len());
+    for c in data_part.chars() {
+        let v = CHARSET
+            .iter()
+            .position(|&x| x as char == c)
+            .ok_or_else(|| format!("invalid bech32 character: {}", c))?;
+        values.push(v as u8);
+    }
+
+    if !verify_checksum(hrp, &values) {
+        return Err("invalid bech32 checksum".to_string());
+    }
+
+    let data = &values[..values.len() - 6];
+    convert_bits(data, 5, 8, false)
+}
@@ -0,0 +1,63 @@
+//! Nibble-lookup hex encode/decode. The `hex` crate is byte-oriented and
+//! shows up in profiles once batch hashing re-encodes thousands of digests
+//! per call; this table-driven path avoids its per-byte formatting overhead.
+//! Only compiled in when the `fast-hex` feature is enabled so crates that
+//! don't need the extra codegen can skip it.
+
+const HEX_LOWER: &[u8; 16] = b"0123456789abcdef";
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+fn encode_with_table(data: &[u8], table: &[u8; 16]) -> String {
+    let mut out = Vec::with_capacity(data.len() * 2);
+    for &byte in data {
+        out.push(table[(byte >> 4) as usize]);
+        out.push(table[(byte & 0x0f) as usize]);
+    }
+    String::from_utf8(out).expect("hex table output is always ASCII")
+}
+
+/// Encodes `data` as a lowercase hex string.
+pub fn encode(data: &[u8]) -> String {
+    encode_with_table(data, HEX_LOWER)
+}
+
+/// Encodes `data` as an uppercase hex string.
+pub fn encode_upper(data: &[u8]) -> String {
+    encode_with_table(data, HEX_UPPER)
+}
+
+fn hex_val(c: u8) -> Result<u8, String> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        other => Err(format!("Invalid hex character: {}", other as char)),
+    }
+}
+
+/// Decodes a hex string into bytes. Tolerates a leading `0x`/`0X` prefix and
+/// an odd number of digits, treating the latter as if left-padded with a
+/// zero nibble (e.g. `"f"` decodes to `[0x0f]`, `"abc"` to `[0x0a, 0xbc]`).
+pub fn decode(input: &str) -> Result<Vec<u8>, String> {
+    let input = input
+        .strip_prefix("0x")
+        .or_else(|| input.strip_prefix("0X"))
+        .unwrap_or(input);
+
+    let padded;
+    let bytes = if input.len() % 2 == 1 {
+        padded = format!("0{}", input);
+        padded.into_bytes()
+    } else {
+        input.as_bytes().to_vec()
+    };
+
+    let mut out = Vec::with_capacity(bytes.len() / 2);
+    for pair in bytes.chunks_exact(2) {
+        let hi = hex_val(pair[0])?;
+        let lo = hex_val(pair[1])?;
+        out.push((hi << 4) | lo);
+    }
+
+    Ok(out)
+}
@@ -0,0 +1,181 @@
+use wasm_bindgen::prelude::*;
+use xelis_hash::v2::{self, ScratchPad};
+use std::sync::Mutex;
+
+// Thread-local scratchpad reused across XELIS v2 hashes (all but those of the
+// parallel batch path, which uses `pool`) so sequential batching and mining
+// don't pay for a fresh allocation on each call.
+thread_local! {
+    static SCRATCH_PAD: Mutex<ScratchPad> = Mutex::new(ScratchPad::default());
+}
+
+/// Hash backends selectable from JS. `XelisV2` is the crate's original
+/// PoW-oriented hash; `Blake3` and `Xxh3` are fast general-purpose hashes for
+/// checksumming or deduping data without needing a separate binding.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    XelisV2,
+    Blake3,
+    Xxh3,
+}
+
+impl Default for HashAlgorithm {
+    fn default() -> Self {
+        HashAlgorithm::XelisV2
+    }
+}
+
+impl HashAlgorithm {
+    /// Digest size in bytes produced by this algorithm.
+    pub fn output_size(&self) -> usize {
+        match self {
+            HashAlgorithm::XelisV2 => 32,
+            HashAlgorithm::Blake3 => blake3::OUT_LEN,
+            HashAlgorithm::Xxh3 => 8,
+        }
+    }
+}
+
+/// Common interface every supported hash backend implements, so the WASM
+/// bindings can dispatch on a `HashAlgorithm` without special-casing each one.
+pub trait Hasher {
+    fn update(&mut self, data: &[u8]);
+    fn finalize(self: Box<Self>) -> Result<Vec<u8>, String>;
+}
+
+struct XelisV2Hasher {
+    buffer: Vec<u8>,
+}
+
+impl Hasher for XelisV2Hasher {
+    fn update(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    fn finalize(self: Box<Self>) -> Result<Vec<u8>, String> {
+        hash_xelis_v2_raw(&self.buffer)
+    }
+}
+
+/// Hashes `data` directly against the shared scratchpad, bypassing the
+/// `Hasher` trait's buffering. Used by [`XelisV2Hasher::finalize`] and by the
+/// mining loop, which mutates one header buffer in place across iterations
+/// and doesn't need an extra copy per attempt.
+pub fn hash_xelis_v2_raw(data: &[u8]) -> Result<Vec<u8>, String> {
+    SCRATCH_PAD.with(|scratch_pad| {
+        let mut scratch_pad = scratch_pad.lock().unwrap();
+        v2::xelis_hash(data, &mut scratch_pad)
+            .map(|hash| hash.to_vec())
+            .map_err(|e| format!("Hashing error: {:?}", e))
+    })
+}
+
+/// [`hash_xelis_v2_raw`] with the error already turned into a `JsValue`, for
+/// callers sitting right behind a `#[wasm_bindgen]` boundary.
+pub fn hash_xelis_v2(data: &[u8]) -> Result<Vec<u8>, JsValue> {
+    hash_xelis_v2_raw(data).map_err(|e| JsValue::from_str(&e))
+}
+
+struct Blake3Hasher {
+    hasher: blake3::Hasher,
+}
+
+impl Hasher for Blake3Hasher {
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    fn finalize(self: Box<Self>) -> Result<Vec<u8>, String> {
+        Ok(self.hasher.finalize().as_bytes().to_vec())
+    }
+}
+
+struct Xxh3Hasher {
+    hasher: xxhash_rust::xxh3::Xxh3,
+}
+
+impl Hasher for Xxh3Hasher {
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    fn finalize(self: Box<Self>) -> Result<Vec<u8>, String> {
+        Ok(self.hasher.digest().to_be_bytes().to_vec())
+    }
+}
+
+/// Builds the backend for `algorithm`.
+fn make_hasher(algorithm: HashAlgorithm) -> Box<dyn Hasher> {
+    match algorithm {
+        HashAlgorithm::XelisV2 => Box::new(XelisV2Hasher { buffer: Vec::new() }),
+        HashAlgorithm::Blake3 => Box::new(Blake3Hasher {
+            hasher: blake3::Hasher::new(),
+        }),
+        HashAlgorithm::Xxh3 => Box::new(Xxh3Hasher {
+            hasher: xxhash_rust::xxh3::Xxh3::new(),
+        }),
+    }
+}
+
+/// Hashes `data` in one shot with the given algorithm, without converting
+/// the error to a `JsValue`. `JsValue` isn't `Send`, so this is the variant
+/// parallel batch hashing uses across worker threads.
+pub fn hash_raw(data: &[u8], algorithm: HashAlgorithm) -> Result<Vec<u8>, String> {
+    let mut hasher = make_hasher(algorithm);
+    hasher.update(data);
+    hasher.finalize()
+}
+
+/// Hashes `data` in one shot with the given algorithm. The single-input and
+/// sequential batch WASM hash functions dispatch through here; the parallel
+/// batch path uses [`hash_raw`] and the scratchpad pool, and the mining loop
+/// uses [`hash_xelis_v2`]. A new backend needs a `HashAlgorithm` variant, a
+/// `Hasher` impl, and arms in `output_size` and `make_hasher`.
+pub fn hash(data: &[u8], algorithm: HashAlgorithm) -> Result<Vec<u8>, JsValue> {
+    hash_raw(data, algorithm).map_err(|e| JsValue::from_str(&e))
+}
+
+/// A small pool of `ScratchPad`s sized to available parallelism, so
+/// `batch_hash_parallel` can give each worker its own pad instead of
+/// contending on a single mutexed one.
+#[cfg(feature = "parallel")]
+pub mod pool {
+    use super::*;
+    use std::sync::OnceLock;
+
+    struct ScratchPadPool(Vec<Mutex<ScratchPad>>);
+
+    impl ScratchPadPool {
+        fn new() -> Self {
+            let size = std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1);
+            Self((0..size).map(|_| Mutex::new(ScratchPad::default())).collect())
+        }
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    fn pool() -> &'static ScratchPadPool {
+        static POOL: OnceLock<ScratchPadPool> = OnceLock::new();
+        POOL.get_or_init(ScratchPadPool::new)
+    }
+
+    /// Number of scratchpads in the pool, i.e. how many XELIS v2 hashes can
+    /// run concurrently without contending on the same scratchpad.
+    pub fn size() -> usize {
+        pool().len()
+    }
+
+    /// Hashes `data` using the scratchpad at `slot % size()`.
+    pub fn hash_xelis_v2(data: &[u8], slot: usize) -> Result<Vec<u8>, String> {
+        let pool = pool();
+        let mut scratch_pad = pool.0[slot % pool.len()].lock().unwrap();
+        v2::xelis_hash(data, &mut scratch_pad)
+            .map(|hash| hash.to_vec())
+            .map_err(|e| format!("Hashing error: {:?}", e))
+    }
+}
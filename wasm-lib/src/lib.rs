@@ -1,8 +1,60 @@
 use wasm_bindgen::prelude::*;
-use xelis_hash::v2::{self, ScratchPad};
 use hex;
 use js_sys;
-use std::sync::Mutex;
+
+mod hasher;
+use hasher::HashAlgorithm;
+
+mod mine;
+pub use mine::mine;
+
+mod bech32;
+
+mod batch;
+
+#[cfg(feature = "fast-hex")]
+mod fast_hex;
+
+// Encoding/decoding shims so the rest of the crate doesn't need to care
+// whether the `fast-hex` feature is enabled.
+#[cfg(feature = "fast-hex")]
+fn encode_hex(data: &[u8]) -> String {
+    fast_hex::encode(data)
+}
+#[cfg(not(feature = "fast-hex"))]
+fn encode_hex(data: &[u8]) -> String {
+    hex::encode(data)
+}
+
+#[cfg(feature = "fast-hex")]
+fn encode_hex_upper(data: &[u8]) -> String {
+    fast_hex::encode_upper(data)
+}
+#[cfg(not(feature = "fast-hex"))]
+fn encode_hex_upper(data: &[u8]) -> String {
+    hex::encode_upper(data)
+}
+
+#[cfg(feature = "fast-hex")]
+fn decode_hex(s: &str) -> Result<Vec<u8>, JsValue> {
+    fast_hex::decode(s).map_err(|e| JsValue::from_str(&e))
+}
+#[cfg(not(feature = "fast-hex"))]
+fn decode_hex(s: &str) -> Result<Vec<u8>, JsValue> {
+    // Tolerate a `0x`/`0X` prefix and an odd digit count even without the
+    // fast-hex feature, since these are parsing conveniences rather than a
+    // performance concern.
+    let stripped = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")).unwrap_or(s);
+    let padded;
+    let normalized = if stripped.len() % 2 == 1 {
+        padded = format!("0{}", stripped);
+        padded.as_str()
+    } else {
+        stripped
+    };
+    hex::decode(normalized)
+        .map_err(|e| JsValue::from_str(&format!("Invalid hex string: {}", e)))
+}
 
 // Enable console error panic hook for better error messages
 #[wasm_bindgen]
@@ -11,132 +63,181 @@ pub fn init_panic_hook() {
     console_error_panic_hook::set_once();
 }
 
-// Thread-local scratchpad for hashing
-thread_local! {
-    static SCRATCH_PAD: Mutex<ScratchPad> = Mutex::new(ScratchPad::default());
-}
-
-// Hash function that returns bytes as Vec<u8>
+// Hash function that returns bytes as Vec<u8>. `algorithm` defaults to the
+// crate's original XELIS v2 hash when omitted.
 #[wasm_bindgen]
-pub fn xelis_hash(data: &[u8]) -> Result<Vec<u8>, JsValue> {
-    SCRATCH_PAD.with(|scratch_pad| {
-        let mut scratch_pad = scratch_pad.lock().unwrap();
-        v2::xelis_hash(data, &mut scratch_pad)
-            .map(|hash| hash.to_vec())
-            .map_err(|e| JsValue::from_str(&format!("Hashing error: {:?}", e)))
-    })
+pub fn xelis_hash(data: &[u8], algorithm: Option<HashAlgorithm>) -> Result<Vec<u8>, JsValue> {
+    hasher::hash(data, algorithm.unwrap_or_default())
 }
 
 // Hash function that returns a hex string
 #[wasm_bindgen]
-pub fn xelis_hash_hex(data: &[u8]) -> Result<String, JsValue> {
-    xelis_hash(data).map(|hash| hex::encode(hash))
+pub fn xelis_hash_hex(data: &[u8], algorithm: Option<HashAlgorithm>) -> Result<String, JsValue> {
+    xelis_hash(data, algorithm).map(|hash| encode_hex(&hash))
 }
 
 // Helper function to convert JavaScript string to bytes and hash
 #[wasm_bindgen]
-pub fn hash_string(input: &str) -> Result<String, JsValue> {
-    xelis_hash_hex(input.as_bytes())
+pub fn hash_string(input: &str, algorithm: Option<HashAlgorithm>) -> Result<String, JsValue> {
+    xelis_hash_hex(input.as_bytes(), algorithm)
 }
 
 // Helper function to hash multiple times
 #[wasm_bindgen]
-pub fn xelis_hash_multiple(data: &[u8], iterations: u32) -> Result<Vec<u8>, JsValue> {
+pub fn xelis_hash_multiple(
+    data: &[u8],
+    iterations: u32,
+    algorithm: Option<HashAlgorithm>,
+) -> Result<Vec<u8>, JsValue> {
     if iterations == 0 {
         return Ok(data.to_vec());
     }
 
-    let mut result = xelis_hash(data)?;
-    
+    let algorithm = algorithm.unwrap_or_default();
+    let mut result = hasher::hash(data, algorithm)?;
+
     for _ in 1..iterations {
-        result = xelis_hash(&result)?;
+        result = hasher::hash(&result, algorithm)?;
     }
-    
+
     Ok(result)
 }
 
 // Convert a hash from bytes back to hex string
 #[wasm_bindgen]
 pub fn bytes_to_hex(data: &[u8]) -> String {
-    hex::encode(data)
+    encode_hex(data)
+}
+
+// Convert a hash from bytes back to an uppercase hex string
+#[wasm_bindgen]
+pub fn bytes_to_hex_upper(data: &[u8]) -> String {
+    encode_hex_upper(data)
 }
 
-// Convert hex string to bytes
+// Convert hex string to bytes. Tolerates a leading `0x`/`0X` prefix and an
+// odd number of digits (treated as left-padded with a zero nibble).
 #[wasm_bindgen]
 pub fn hex_to_bytes(hex_string: &str) -> Result<Vec<u8>, JsValue> {
-    hex::decode(hex_string)
-        .map_err(|e| JsValue::from_str(&format!("Invalid hex string: {}", e)))
+    decode_hex(hex_string)
+}
+
+// Encode data as bech32 with a human-readable prefix, e.g. for rendering a
+// digest in XELIS's checksummed, copy-safe address/identifier form.
+#[wasm_bindgen]
+pub fn hash_to_bech32(data: &[u8], hrp: &str) -> Result<String, JsValue> {
+    bech32::encode(hrp, data).map_err(|e| JsValue::from_str(&e))
+}
+
+// Decode a bech32 string back into its data bytes, validating the checksum.
+#[wasm_bindgen]
+pub fn bech32_to_bytes(s: &str) -> Result<Vec<u8>, JsValue> {
+    bech32::decode(s).map_err(|e| JsValue::from_str(&e))
 }
 
-// Verify if two hashes (in hex format) are equal
+// Verify if two hashes (in hex format) are equal, in constant time so
+// verifying PoW solutions or commitments doesn't leak timing information
+// about where the mismatch occurs.
 #[wasm_bindgen]
 pub fn verify_hash(hex_hash1: &str, hex_hash2: &str) -> Result<bool, JsValue> {
     let bytes1 = hex::decode(hex_hash1)
         .map_err(|e| JsValue::from_str(&format!("Invalid first hash: {}", e)))?;
-    
+
     let bytes2 = hex::decode(hex_hash2)
         .map_err(|e| JsValue::from_str(&format!("Invalid second hash: {}", e)))?;
-    
-    Ok(bytes1 == bytes2)
+
+    Ok(verify_hash_bytes(&bytes1, &bytes2))
+}
+
+// Constant-time comparison of two raw hash byte slices. The length check
+// short-circuits (lengths aren't secret), but every byte pair within the
+// common length is compared regardless of earlier mismatches.
+#[wasm_bindgen]
+pub fn verify_hash_bytes(bytes1: &[u8], bytes2: &[u8]) -> bool {
+    if bytes1.len() != bytes2.len() {
+        return false;
+    }
+
+    let mut acc = 0u8;
+    for (a, b) in bytes1.iter().zip(bytes2.iter()) {
+        acc |= a ^ b;
+    }
+
+    acc == 0
 }
 
-// Get the size of the hash in bytes
+// Get the size of the hash in bytes produced by `algorithm` (defaults to XELIS v2)
 #[wasm_bindgen]
-pub fn get_hash_size() -> usize {
-    32
+pub fn get_hash_size(algorithm: Option<HashAlgorithm>) -> usize {
+    algorithm.unwrap_or_default().output_size()
 }
 
-// Advanced function that returns both bytes and hex
+// Default human-readable prefix used to bech32-encode a hash when the
+// caller doesn't supply one of their own.
+const DEFAULT_HASH_HRP: &str = "xel";
+
+// Advanced function that returns bytes, hex, and bech32 forms of a hash
 #[wasm_bindgen]
-pub fn xelis_hash_detailed(data: &[u8]) -> Result<JsValue, JsValue> {
-    let hash_bytes = xelis_hash(data)?;
-    let hex_string = hex::encode(&hash_bytes);
-    
+pub fn xelis_hash_detailed(data: &[u8], hrp: Option<String>) -> Result<JsValue, JsValue> {
+    let hash_bytes = xelis_hash(data, None)?;
+    let hex_string = encode_hex(&hash_bytes);
+    let bech32_string = bech32::encode(hrp.as_deref().unwrap_or(DEFAULT_HASH_HRP), &hash_bytes)
+        .map_err(|e| JsValue::from_str(&e))?;
+
     let result = js_sys::Object::new();
-    
+
     js_sys::Reflect::set(
         &result,
         &"bytes".into(),
         &js_sys::Uint8Array::from(&hash_bytes[..]).into()
     ).map_err(|_| JsValue::from_str("Failed to set bytes property"))?;
-    
+
     js_sys::Reflect::set(
         &result,
         &"hex".into(),
         &hex_string.into()
     ).map_err(|_| JsValue::from_str("Failed to set hex property"))?;
-    
+
+    js_sys::Reflect::set(
+        &result,
+        &"bech32".into(),
+        &bech32_string.into()
+    ).map_err(|_| JsValue::from_str("Failed to set bech32 property"))?;
+
     js_sys::Reflect::set(
         &result,
         &"size".into(),
         &(hash_bytes.len() as u32).into()
     ).map_err(|_| JsValue::from_str("Failed to set size property"))?;
-    
+
     Ok(result.into())
 }
 
-// Batch hashing multiple inputs
+// Batch hashing multiple inputs, sequentially against the shared scratchpad
 #[wasm_bindgen]
-pub fn batch_hash(data_slices: js_sys::Array) -> Result<js_sys::Array, JsValue> {
-    let results = js_sys::Array::new();
-    
-    for i in 0..data_slices.length() {
-        let data = data_slices.get(i).dyn_into::<js_sys::Uint8Array>()
-            .map_err(|_| JsValue::from_str("Failed to convert input to Uint8Array"))?;
-            
-        let bytes = data.to_vec();
-        let hash_result = xelis_hash(&bytes)?;
-        results.push(&js_sys::Uint8Array::from(&hash_result[..]));
-    }
-    
-    Ok(results)
+pub fn batch_hash(
+    data_slices: js_sys::Array,
+    algorithm: Option<HashAlgorithm>,
+) -> Result<js_sys::Array, JsValue> {
+    batch::batch_hash(&data_slices, algorithm.unwrap_or_default())
+}
+
+// Batch hashing multiple inputs across a pool of scratchpads sized to
+// available parallelism (requires the `parallel` feature and a
+// `wasm-bindgen-rayon` thread pool; otherwise behaves like `batch_hash`)
+#[wasm_bindgen]
+pub fn batch_hash_parallel(
+    data_slices: js_sys::Array,
+    algorithm: Option<HashAlgorithm>,
+) -> Result<js_sys::Array, JsValue> {
+    batch::batch_hash_parallel(&data_slices, algorithm.unwrap_or_default())
 }
 
 // Hash and return as both hex and bytes for a single input
 #[wasm_bindgen]
-pub fn hash_with_metadata(data: &[u8]) -> Result<JsValue, JsValue> {
-    let hash_bytes = xelis_hash(data)?;
-    let hash_hex = hex::encode(&hash_bytes);
+pub fn hash_with_metadata(data: &[u8], algorithm: Option<HashAlgorithm>) -> Result<JsValue, JsValue> {
+    let hash_bytes = xelis_hash(data, algorithm)?;
+    let hash_hex = encode_hex(&hash_bytes);
     
     let obj = js_sys::Object::new();
     
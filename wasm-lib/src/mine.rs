@@ -0,0 +1,121 @@
+use wasm_bindgen::prelude::*;
+use js_sys;
+
+use crate::hasher;
+
+/// Difficulty target a mined digest must satisfy.
+enum Target {
+    /// 256-bit big-endian threshold: the digest, read big-endian, must be
+    /// less than or equal to this value.
+    Threshold([u8; 32]),
+    /// Minimum number of leading zero bits the digest must have.
+    LeadingZeroBits(u32),
+}
+
+/// Parses `target_hex` as either a 64-hex-digit (32-byte) big-endian
+/// threshold, or (if it isn't 64 hex digits) a decimal leading-zero-bit count.
+fn parse_target(target_hex: &str) -> Result<Target, JsValue> {
+    let stripped = target_hex.strip_prefix("0x").unwrap_or(target_hex);
+
+    if stripped.len() == 64 && stripped.chars().all(|c| c.is_ascii_hexdigit()) {
+        let bytes = hex::decode(stripped)
+            .map_err(|e| JsValue::from_str(&format!("Invalid target_hex: {}", e)))?;
+        let mut target = [0u8; 32];
+        target.copy_from_slice(&bytes);
+        return Ok(Target::Threshold(target));
+    }
+
+    stripped
+        .parse::<u32>()
+        .map(Target::LeadingZeroBits)
+        .map_err(|_| {
+            JsValue::from_str(
+                "target_hex must be a 64-hex-digit threshold or a leading-zero-bit count",
+            )
+        })
+}
+
+/// Counts leading zero bits across a digest, counting full zero bytes first
+/// and then the high bits of the first nonzero byte.
+fn leading_zero_bits(digest: &[u8]) -> u32 {
+    let mut count = 0u32;
+    for &byte in digest {
+        if byte == 0 {
+            count += 8;
+        } else {
+            count += byte.leading_zeros();
+            break;
+        }
+    }
+    count
+}
+
+fn meets_target(digest: &[u8; 32], target: &Target) -> bool {
+    match target {
+        Target::Threshold(threshold) => digest.as_slice() <= threshold.as_slice(),
+        Target::LeadingZeroBits(bits) => leading_zero_bits(digest) >= *bits,
+    }
+}
+
+/// Searches for a nonce making `header`'s XELIS v2 hash meet `target_hex`,
+/// entirely inside WASM so a JS worker doesn't pay a round-trip per attempt.
+///
+/// On each iteration the little-endian `nonce` is written into
+/// `header[nonce_offset..nonce_offset + 8]` before hashing. Stops after
+/// `max_iterations` (found or not) so the caller can keep the event loop
+/// responsive and resume mining from the reported nonce.
+#[wasm_bindgen]
+pub fn mine(
+    header: &[u8],
+    nonce_offset: usize,
+    target_hex: &str,
+    max_iterations: u32,
+) -> Result<JsValue, JsValue> {
+    let target = parse_target(target_hex)?;
+
+    let nonce_end = nonce_offset
+        .checked_add(8)
+        .filter(|&end| end <= header.len())
+        .ok_or_else(|| JsValue::from_str("nonce_offset + 8 exceeds header length"))?;
+
+    let mut header = header.to_vec();
+    let mut nonce = u64::from_le_bytes(header[nonce_offset..nonce_end].try_into().unwrap());
+
+    let mut found = false;
+    let mut iterations = 0u32;
+    let mut digest = Vec::new();
+
+    while iterations < max_iterations {
+        header[nonce_offset..nonce_end].copy_from_slice(&nonce.to_le_bytes());
+        digest = hasher::hash_xelis_v2(&header)?;
+        iterations += 1;
+
+        let digest_arr: [u8; 32] = digest
+            .as_slice()
+            .try_into()
+            .map_err(|_| JsValue::from_str("XELIS v2 digest was not 32 bytes"))?;
+
+        if meets_target(&digest_arr, &target) {
+            found = true;
+            break;
+        }
+
+        nonce = nonce.wrapping_add(1);
+    }
+
+    let result = js_sys::Object::new();
+
+    js_sys::Reflect::set(&result, &"found".into(), &found.into())
+        .map_err(|_| JsValue::from_str("Failed to set found property"))?;
+
+    js_sys::Reflect::set(&result, &"nonce".into(), &JsValue::from(nonce))
+        .map_err(|_| JsValue::from_str("Failed to set nonce property"))?;
+
+    js_sys::Reflect::set(&result, &"hash_hex".into(), &crate::encode_hex(&digest).into())
+        .map_err(|_| JsValue::from_str("Failed to set hash_hex property"))?;
+
+    js_sys::Reflect::set(&result, &"iterations".into(), &iterations.into())
+        .map_err(|_| JsValue::from_str("Failed to set iterations property"))?;
+
+    Ok(result.into())
+}